@@ -0,0 +1,12 @@
+/// Alignment used to pad a formatted value up to a minimum field width, mirroring
+/// [`core::fmt::Alignment`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum Alignment {
+    /// Pad on the right, so the formatted value is flush with the left edge of the field.
+    Left,
+    /// Pad on the left, so the formatted value is flush with the right edge of the field.
+    Right,
+    /// Pad evenly on both sides, centering the formatted value within the field.
+    Center,
+}
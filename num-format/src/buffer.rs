@@ -0,0 +1,102 @@
+use core::fmt;
+use core::str;
+
+use crate::constants::MAX_BUF_LEN;
+use crate::current::current;
+use crate::float_format_options::FloatFormatOptions;
+use crate::format::Format;
+use crate::to_formatted_str::ToFormattedStr;
+
+/// A stack-allocated buffer you can use to get a formatted `&str` without heap allocating,
+/// suitable for `no_std` use.
+#[derive(Clone, Copy)]
+pub struct Buffer {
+    inner: [u8; MAX_BUF_LEN],
+    len: usize,
+}
+
+impl Buffer {
+    /// Constructs a new, empty `Buffer`.
+    pub fn new() -> Buffer {
+        Buffer::default()
+    }
+
+    /// Writes `value` into this buffer, formatted according to `format`. Returns the number of
+    /// bytes written.
+    pub fn write_formatted<N, F>(&mut self, value: &N, format: &F) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        self.write_formatted_with_options(value, format, FloatFormatOptions::default())
+    }
+
+    /// Like [`write_formatted`](Buffer::write_formatted), but with explicit control over how any
+    /// fractional part is rendered; see [`FloatFormatOptions`]. Integer types ignore `options`,
+    /// since they have no fractional part.
+    pub fn write_formatted_with_options<N, F>(
+        &mut self,
+        value: &N,
+        format: &F,
+        options: FloatFormatOptions,
+    ) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        self.len = value.write_formatted_str(format, options, &mut self.inner);
+        self.len
+    }
+
+    /// Like [`write_formatted`](Buffer::write_formatted), but formatted according to the
+    /// process-wide [`current`] locale instead of an explicit [`Format`].
+    pub fn write_formatted_default<N>(&mut self, value: &N) -> usize
+    where
+        N: ToFormattedStr,
+    {
+        self.write_formatted(value, &current())
+    }
+
+    /// Returns a `&str` view of the formatted value currently held in this buffer, or `""` if
+    /// nothing has been written yet.
+    pub fn as_str(&self) -> &str {
+        // Safe because `write_formatted_str` only ever writes valid utf8 into `self.inner`.
+        unsafe { str::from_utf8_unchecked(&self.inner[..self.len]) }
+    }
+
+    /// Returns a `&[u8]` view of the formatted value currently held in this buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner[..self.len]
+    }
+
+    /// Returns the number of bytes currently held in this buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written into this buffer yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Buffer {
+        Buffer {
+            inner: [0; MAX_BUF_LEN],
+            len: 0,
+        }
+    }
+}
+
+impl fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,39 @@
+//! Buffer-sizing constants shared by [`Buffer`](crate::buffer::Buffer) and the write path in
+//! [`impls`](crate::impls).
+
+/// Maximum number of digits in the integer part of any value this crate can format, in any
+/// supported [`radix`](crate::custom_format_builder::CustomFormatBuilder::radix). This is
+/// `u128::MAX` written in binary (the most digits any supported radix can produce).
+pub(crate) const MAX_INTEGER_DIGITS: usize = 128;
+
+/// Maximum byte length of any one of the small, configurable pieces of a [`Format`](crate::format::Format)
+/// (the separator, the decimal point, the minus sign, ...); see `strings.rs`.
+pub(crate) const MAX_PIECE_LEN: usize = 16;
+
+/// Upper bound on the number of group separators that can appear in the integer part: at most
+/// one every 2 digits (the tightest grouping this crate supports). This overcounts by at least
+/// one, since the leading group never has a separator before it.
+pub(crate) const MAX_GROUP_SEPARATORS: usize = MAX_INTEGER_DIGITS / 2;
+
+/// Maximum number of fraction digits this crate will ever render, regardless of what a caller
+/// asks for via [`FloatFormatOptions`](crate::float_format_options::FloatFormatOptions). This
+/// keeps [`Buffer`](crate::buffer::Buffer) a fixed-size, stack-allocated, `no_std`-friendly type.
+pub(crate) const MAX_FRACTION_DIGITS: usize = 32;
+
+/// Maximum field width [`CustomFormatBuilder::width`](crate::custom_format_builder::CustomFormatBuilder::width)
+/// accepts; wider requests make [`build`](crate::custom_format_builder::CustomFormatBuilder::build)
+/// return an [`InvalidWidth`](crate::error_kind::ErrorKind::InvalidWidth) error. This keeps [`Buffer`](crate::buffer::Buffer)
+/// a fixed-size, stack-allocated, `no_std`-friendly type.
+pub(crate) const MAX_WIDTH: usize = 64;
+
+/// Maximum byte length of a single `char` fill character.
+pub(crate) const MAX_FILL_LEN: usize = 4;
+
+/// Total capacity, in bytes, of [`Buffer`](crate::buffer::Buffer): sign + integer digits + group
+/// separators + decimal separator + fraction digits + field-width padding.
+pub(crate) const MAX_BUF_LEN: usize = MAX_PIECE_LEN
+    + MAX_INTEGER_DIGITS
+    + MAX_GROUP_SEPARATORS * MAX_PIECE_LEN
+    + MAX_PIECE_LEN
+    + MAX_FRACTION_DIGITS
+    + MAX_WIDTH * MAX_FILL_LEN;
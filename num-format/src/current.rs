@@ -0,0 +1,153 @@
+//! A process-wide default [`Locale`], detected once from the environment, with an optional
+//! thread-local override — so callers don't have to thread a [`Locale`]/[`SystemLocale`] through
+//! every call site.
+//!
+//! [`SystemLocale`]: crate::system_locale::SystemLocale
+
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use crate::locale::Locale;
+
+#[cfg(feature = "std")]
+lazy_static! {
+    static ref CURRENT: Mutex<Locale> = Mutex::new(detect());
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static THREAD_OVERRIDE: RefCell<Option<Locale>> = RefCell::new(None);
+}
+
+/// Returns the process-wide default locale: the current thread's override (see
+/// [`set_current_for_thread`]) if one is set, otherwise the value set by [`set_current`],
+/// otherwise whatever was auto-detected from the environment the first time this was called.
+///
+/// Without the `std` feature, there's no environment or thread-local storage to consult, so this
+/// always returns [`Locale::en`].
+pub fn current() -> Locale {
+    #[cfg(feature = "std")]
+    {
+        if let Some(locale) = THREAD_OVERRIDE.with(|cell| *cell.borrow()) {
+            return locale;
+        }
+        *CURRENT.lock().unwrap()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Locale::en
+    }
+}
+
+/// Overrides the process-wide default locale returned by [`current`], for every thread that
+/// doesn't have its own override set via [`set_current_for_thread`].
+#[cfg(feature = "std")]
+pub fn set_current(locale: Locale) {
+    *CURRENT.lock().unwrap() = locale;
+}
+
+/// Overrides the default locale returned by [`current`] for the current thread only, for as
+/// long as the returned [`ThreadLocaleGuard`] stays alive; the previous thread-local override (if
+/// any) is restored when it's dropped.
+#[cfg(feature = "std")]
+pub fn set_current_for_thread(locale: Locale) -> ThreadLocaleGuard {
+    let previous = THREAD_OVERRIDE.with(|cell| cell.replace(Some(locale)));
+    ThreadLocaleGuard { previous }
+}
+
+/// A guard returned by [`set_current_for_thread`] that restores the previous thread-local
+/// override when dropped.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ThreadLocaleGuard {
+    previous: Option<Locale>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for ThreadLocaleGuard {
+    fn drop(&mut self) {
+        THREAD_OVERRIDE.with(|cell| *cell.borrow_mut() = self.previous);
+    }
+}
+
+/// Detects the locale to use by default, the same way [`SystemLocale::default`](crate::system_locale::SystemLocale::default)
+/// does: first by asking the operating system (`locale`/`uselocale` on Unix,
+/// `GetUserDefaultLocaleName` on Windows), then, if that fails or isn't available, by inspecting
+/// `LC_ALL`, `LC_NUMERIC`, and `LANG` (in that order), and finally falling back to [`Locale::en`].
+#[cfg(feature = "std")]
+fn detect() -> Locale {
+    #[cfg(any(unix, windows))]
+    {
+        if let Ok(system_locale) = crate::system_locale::SystemLocale::default() {
+            if let Ok(locale) = Locale::from_name(system_locale.name()) {
+                return locale;
+            }
+        }
+    }
+
+    for var in ["LC_ALL", "LC_NUMERIC", "LANG"].iter() {
+        if let Ok(value) = std::env::var(var) {
+            // Strip off an `".UTF-8"`-style encoding suffix, if present.
+            let name = value.split('.').next().unwrap_or(&value);
+            if let Ok(locale) = Locale::from_name(name) {
+                return locale;
+            }
+        }
+    }
+
+    Locale::en
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    // `CURRENT` is process-wide global state; serialize every test that reads or writes it
+    // (directly via `set_current`, or indirectly since `current()` falls back to it) so they
+    // can't interleave and observe each other's intermediate state under `cargo test`'s default
+    // parallelism.
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn thread_override_is_restored_on_drop() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let before = current();
+        {
+            let _guard = set_current_for_thread(Locale::fr);
+            assert_eq!(current(), Locale::fr);
+            {
+                let _nested_guard = set_current_for_thread(Locale::de);
+                assert_eq!(current(), Locale::de);
+            }
+            assert_eq!(current(), Locale::fr);
+        }
+        assert_eq!(current(), before);
+    }
+
+    #[test]
+    fn set_current_overrides_the_process_wide_default() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let before = current();
+        set_current(Locale::ja);
+        assert_eq!(current(), Locale::ja);
+        set_current(before);
+        assert_eq!(current(), before);
+    }
+
+    #[test]
+    fn thread_override_takes_priority_over_the_process_wide_default() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let before = current();
+        set_current(Locale::ja);
+        {
+            let _guard = set_current_for_thread(Locale::de);
+            assert_eq!(current(), Locale::de);
+        }
+        assert_eq!(current(), Locale::ja);
+        set_current(before);
+    }
+}
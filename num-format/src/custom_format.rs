@@ -0,0 +1,129 @@
+use crate::alignment::Alignment;
+use crate::custom_format_builder::CustomFormatBuilder;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::sealed::Sealed;
+use crate::strings::{
+    DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
+    PlusSignStr, PlusString, SepString, SeparatorStr,
+};
+
+/// A format you build yourself, for when [`Locale`] and [`SystemLocale`] don't cut it.
+///
+/// [`Locale`]: enum.Locale.html
+/// [`SystemLocale`]: struct.SystemLocale.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct CustomFormat {
+    pub(crate) dec: DecString,
+    pub(crate) grp: Grouping,
+    pub(crate) inf: InfString,
+    pub(crate) min: MinString,
+    pub(crate) nan: NanString,
+    pub(crate) plus: PlusString,
+    pub(crate) sep: SepString,
+    pub(crate) width: usize,
+    pub(crate) fill: char,
+    pub(crate) alignment: Alignment,
+    pub(crate) radix: u8,
+}
+
+impl CustomFormat {
+    /// Returns a new [`CustomFormatBuilder`].
+    pub fn builder() -> CustomFormatBuilder {
+        CustomFormatBuilder::new()
+    }
+
+    /// Returns the representation of the decimal separator.
+    pub fn decimal(&self) -> &str {
+        &self.dec
+    }
+
+    /// Returns the representation of the grouping strategy.
+    pub fn grouping(&self) -> Grouping {
+        self.grp
+    }
+
+    /// Returns the representation of infinity.
+    pub fn infinity(&self) -> &str {
+        &self.inf
+    }
+
+    /// Returns the representation of the minus sign.
+    pub fn minus_sign(&self) -> &str {
+        &self.min
+    }
+
+    /// Returns the representation of NaN.
+    pub fn nan(&self) -> &str {
+        &self.nan
+    }
+
+    /// Returns the representation of the plus sign.
+    pub fn plus_sign(&self) -> &str {
+        &self.plus
+    }
+
+    /// Returns the representation of the thousands separator.
+    pub fn separator(&self) -> &str {
+        &self.sep
+    }
+
+    /// Returns the minimum field width that formatted values are padded out to.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the fill character used when padding up to [`width`](CustomFormat::width).
+    pub fn fill(&self) -> char {
+        self.fill
+    }
+
+    /// Returns the alignment used when padding up to [`width`](CustomFormat::width).
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Returns the radix (base) integer types are rendered in.
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+}
+
+impl Sealed for CustomFormat {}
+
+impl Format for CustomFormat {
+    fn decimal(&self) -> DecimalStr<'_> {
+        DecimalStr::new(self.decimal()).unwrap()
+    }
+    fn grouping(&self) -> Grouping {
+        self.grouping()
+    }
+    fn infinity(&self) -> InfinityStr<'_> {
+        InfinityStr::new(self.infinity()).unwrap()
+    }
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        MinusSignStr::new(self.minus_sign()).unwrap()
+    }
+    fn nan(&self) -> NanStr<'_> {
+        NanStr::new(self.nan()).unwrap()
+    }
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        PlusSignStr::new(self.plus_sign()).unwrap()
+    }
+    fn separator(&self) -> SeparatorStr<'_> {
+        SeparatorStr::new(self.separator()).unwrap()
+    }
+    fn width(&self) -> usize {
+        self.width()
+    }
+    fn fill(&self) -> char {
+        self.fill()
+    }
+    fn alignment(&self) -> Alignment {
+        self.alignment()
+    }
+    fn radix(&self) -> u8 {
+        self.radix()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::alignment::Alignment;
+use crate::constants::MAX_WIDTH;
+use crate::custom_format::CustomFormat;
+use crate::error::Error;
+use crate::error_kind::ErrorKind;
+use crate::grouping::Grouping;
+use crate::strings::{DecString, InfString, MinString, NanString, PlusString, SepString};
+
+/// A builder for [`CustomFormat`].
+///
+/// Setters can be called in any order and are validated all at once, when [`build`](CustomFormatBuilder::build)
+/// is called.
+#[derive(Clone, Debug)]
+pub struct CustomFormatBuilder {
+    dec: Result<DecString, Error>,
+    grp: Grouping,
+    inf: Result<InfString, Error>,
+    min: Result<MinString, Error>,
+    nan: Result<NanString, Error>,
+    plus: Result<PlusString, Error>,
+    sep: Result<SepString, Error>,
+    width: Result<usize, Error>,
+    fill: char,
+    alignment: Alignment,
+    radix: Result<u8, Error>,
+}
+
+impl CustomFormatBuilder {
+    pub(crate) fn new() -> CustomFormatBuilder {
+        CustomFormatBuilder {
+            dec: DecString::new("."),
+            grp: Grouping::Standard,
+            inf: InfString::new("∞"),
+            min: MinString::new("-"),
+            nan: NanString::new("NaN"),
+            plus: PlusString::new("+"),
+            sep: SepString::new(","),
+            width: Ok(0),
+            fill: ' ',
+            alignment: Alignment::Right,
+            radix: Ok(10),
+        }
+    }
+
+    /// Sets the representation of the decimal separator (e.g. the `.` in `1.5`).
+    pub fn decimal<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.dec = DecString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the grouping strategy.
+    pub fn grouping(mut self, grouping: Grouping) -> CustomFormatBuilder {
+        self.grp = grouping;
+        self
+    }
+
+    /// Sets the representation of infinity.
+    pub fn infinity<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.inf = InfString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the representation of the minus sign.
+    pub fn minus_sign<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.min = MinString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the representation of NaN.
+    pub fn nan<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.nan = NanString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the representation of the plus sign.
+    pub fn plus_sign<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.plus = PlusString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the representation of the thousands separator (e.g. the `,` in `1,000`).
+    pub fn separator<S>(mut self, s: S) -> CustomFormatBuilder
+    where
+        S: AsRef<str>,
+    {
+        self.sep = SepString::new(s.as_ref());
+        self
+    }
+
+    /// Sets the minimum field width; formatted values shorter than `width` are padded with
+    /// [`fill`](CustomFormatBuilder::fill) according to [`alignment`](CustomFormatBuilder::alignment).
+    /// `width` must not exceed this crate's maximum supported field width (64). Defaults to `0`
+    /// (no padding).
+    pub fn width(mut self, width: usize) -> CustomFormatBuilder {
+        self.width = if width <= MAX_WIDTH {
+            Ok(width)
+        } else {
+            Err(Error::new(ErrorKind::InvalidWidth))
+        };
+        self
+    }
+
+    /// Sets the fill character used when padding up to [`width`](CustomFormatBuilder::width).
+    /// Every `char` is a valid fill, so unlike [`width`](CustomFormatBuilder::width) and
+    /// [`radix`](CustomFormatBuilder::radix) this setter cannot fail. Defaults to `' '`.
+    pub fn fill(mut self, fill: char) -> CustomFormatBuilder {
+        self.fill = fill;
+        self
+    }
+
+    /// Sets the alignment used when padding up to [`width`](CustomFormatBuilder::width).
+    /// Defaults to [`Alignment::Right`].
+    pub fn alignment(mut self, alignment: Alignment) -> CustomFormatBuilder {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the radix (base) integer types are rendered in, between 2 and 36 (inclusive). Digits
+    /// above `9` use `'a'`-`'z'`; the locale's grouping and minus sign are applied exactly as
+    /// they are in base 10. Does not affect how floating point types are rendered. Defaults to
+    /// `10`.
+    pub fn radix(mut self, radix: u8) -> CustomFormatBuilder {
+        self.radix = if (2..=36).contains(&radix) {
+            Ok(radix)
+        } else {
+            Err(Error::new(ErrorKind::InvalidRadix(radix)))
+        };
+        self
+    }
+
+    /// Validates the options set so far and, if they're all valid, returns a new
+    /// [`CustomFormat`].
+    pub fn build(self) -> Result<CustomFormat, Error> {
+        Ok(CustomFormat {
+            dec: self.dec?,
+            grp: self.grp,
+            inf: self.inf?,
+            min: self.min?,
+            nan: self.nan?,
+            plus: self.plus?,
+            sep: self.sep?,
+            width: self.width?,
+            fill: self.fill,
+            alignment: self.alignment,
+            radix: self.radix?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn width_exceeding_the_maximum_is_an_error() {
+        assert!(CustomFormat::builder().width(MAX_WIDTH).build().is_ok());
+        assert!(CustomFormat::builder().width(MAX_WIDTH + 1).build().is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use core::fmt;
+
+use crate::error_kind::ErrorKind;
+
+/// The error type for this crate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind) -> Error {
+        Error { kind }
+    }
+
+    /// Returns the corresponding [`ErrorKind`] for this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
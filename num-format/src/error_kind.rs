@@ -0,0 +1,36 @@
+use core::fmt;
+
+/// The particular kind of error that occurred.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A provided string was too long to fit into one of this crate's fixed-capacity string
+    /// types.
+    Capacity(usize),
+    /// A provided radix was outside the supported range of 2 to 36 (inclusive).
+    InvalidRadix(u8),
+    /// A provided width exceeded this crate's maximum supported field width.
+    InvalidWidth,
+    /// No locale could be found matching the name or language range(s) provided.
+    ParseLocale,
+    /// An error originating from the host operating system's locale APIs.
+    #[cfg(feature = "std")]
+    System,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Capacity(max) => {
+                write!(f, "string exceeded this type's capacity of {} byte(s)", max)
+            }
+            ErrorKind::InvalidRadix(radix) => {
+                write!(f, "invalid radix {}; must be between 2 and 36 (inclusive)", radix)
+            }
+            ErrorKind::InvalidWidth => write!(f, "invalid width: exceeds the maximum supported field width"),
+            ErrorKind::ParseLocale => write!(f, "could not parse locale from provided input"),
+            #[cfg(feature = "std")]
+            ErrorKind::System => write!(f, "error calling into the operating system's locale APIs"),
+        }
+    }
+}
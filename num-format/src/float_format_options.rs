@@ -0,0 +1,92 @@
+use crate::constants::{MAX_FRACTION_DIGITS, MAX_INTEGER_DIGITS};
+
+/// Per-call options controlling how a floating point number's fraction digits and minimum
+/// number of integer digits are rendered, mirroring the `minimumFractionDigits` /
+/// `maximumFractionDigits` / minimum integer digits knobs found on Fluent's and ICU's number
+/// formatters.
+///
+/// Apart from the leading zeros added by
+/// [`minimum_integer_digits`](FloatFormatOptions::minimum_integer_digits), the integer part is
+/// unaffected by these options; it is always grouped according to the
+/// [`Format`](crate::format::Format) in use, the same as for the integer types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct FloatFormatOptions {
+    minimum_fraction_digits: u8,
+    maximum_fraction_digits: u8,
+    minimum_integer_digits: u8,
+}
+
+impl FloatFormatOptions {
+    /// Constructs a new `FloatFormatOptions` with the default options (no minimum fraction
+    /// digits, a maximum of 3 fraction digits, and a minimum of 1 integer digit), so e.g.
+    /// `1.23456` renders as `"1.235"` and `1.5` as `"1.5"`.
+    pub fn new() -> FloatFormatOptions {
+        FloatFormatOptions::default()
+    }
+
+    /// Sets the minimum number of fraction digits to render, padding with trailing zeros if
+    /// necessary. Clamped to [`MAX_FRACTION_DIGITS`](crate::constants::MAX_FRACTION_DIGITS).
+    pub fn minimum_fraction_digits(mut self, digits: u8) -> FloatFormatOptions {
+        self.minimum_fraction_digits = digits.min(MAX_FRACTION_DIGITS as u8);
+        if self.maximum_fraction_digits < self.minimum_fraction_digits {
+            self.maximum_fraction_digits = self.minimum_fraction_digits;
+        }
+        self
+    }
+
+    /// Sets the maximum number of fraction digits to render; the fractional part is rounded
+    /// half-to-even (banker's rounding) at this boundary, matching ICU. Clamped to
+    /// [`MAX_FRACTION_DIGITS`](crate::constants::MAX_FRACTION_DIGITS).
+    pub fn maximum_fraction_digits(mut self, digits: u8) -> FloatFormatOptions {
+        self.maximum_fraction_digits = digits.min(MAX_FRACTION_DIGITS as u8);
+        if self.minimum_fraction_digits > self.maximum_fraction_digits {
+            self.minimum_fraction_digits = self.maximum_fraction_digits;
+        }
+        self
+    }
+
+    /// Sets the minimum number of integer digits to render, padding with leading zeros if
+    /// necessary. Clamped to [`MAX_INTEGER_DIGITS`](crate::constants::MAX_INTEGER_DIGITS).
+    pub fn minimum_integer_digits(mut self, digits: u8) -> FloatFormatOptions {
+        self.minimum_integer_digits = digits.min(MAX_INTEGER_DIGITS as u8);
+        self
+    }
+
+    pub(crate) fn min_fraction_digits(&self) -> usize {
+        self.minimum_fraction_digits as usize
+    }
+
+    pub(crate) fn max_fraction_digits(&self) -> usize {
+        self.maximum_fraction_digits as usize
+    }
+
+    pub(crate) fn min_integer_digits(&self) -> usize {
+        self.minimum_integer_digits as usize
+    }
+}
+
+impl Default for FloatFormatOptions {
+    fn default() -> FloatFormatOptions {
+        FloatFormatOptions {
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 3,
+            minimum_integer_digits: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::locale::Locale;
+
+    #[test]
+    fn minimum_integer_digits_is_clamped() {
+        let options = FloatFormatOptions::new().minimum_integer_digits(200);
+        assert_eq!(options.min_integer_digits(), MAX_INTEGER_DIGITS);
+
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted_with_options(&1.5f64, &Locale::en, options);
+    }
+}
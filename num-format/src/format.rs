@@ -0,0 +1,61 @@
+use crate::alignment::Alignment;
+use crate::grouping::Grouping;
+use crate::sealed::Sealed;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+
+/// Implemented by all the types in this crate that represent a "format": [`CustomFormat`],
+/// [`Locale`], and [`SystemLocale`].
+///
+/// [`CustomFormat`]: custom_format/struct.CustomFormat.html
+/// [`Locale`]: enum.Locale.html
+/// [`SystemLocale`]: struct.SystemLocale.html
+pub trait Format: Sealed {
+    /// Representation of the decimal separator (e.g. the `.` in `1.5`).
+    fn decimal(&self) -> DecimalStr<'_>;
+
+    /// Representation of the grouping strategy to use (e.g. `"Standard"`, `"Indian"`, or
+    /// `"Posix"`).
+    fn grouping(&self) -> Grouping;
+
+    /// Representation of infinity.
+    fn infinity(&self) -> InfinityStr<'_>;
+
+    /// Representation of the minus sign.
+    fn minus_sign(&self) -> MinusSignStr<'_>;
+
+    /// Representation of NaN.
+    fn nan(&self) -> NanStr<'_>;
+
+    /// Representation of the plus sign.
+    fn plus_sign(&self) -> PlusSignStr<'_>;
+
+    /// Representation of the thousands separator (e.g. the `,` in `1,000`).
+    fn separator(&self) -> SeparatorStr<'_>;
+
+    /// Minimum field width to pad a formatted value out to. The default implementation returns
+    /// `0`, meaning no padding; only [`CustomFormat`] currently overrides this.
+    ///
+    /// [`CustomFormat`]: custom_format/struct.CustomFormat.html
+    fn width(&self) -> usize {
+        0
+    }
+
+    /// Fill character used to pad a formatted value up to [`width`](Format::width). The default
+    /// implementation returns `' '`.
+    fn fill(&self) -> char {
+        ' '
+    }
+
+    /// Alignment used to pad a formatted value up to [`width`](Format::width). The default
+    /// implementation returns [`Alignment::Right`].
+    fn alignment(&self) -> Alignment {
+        Alignment::Right
+    }
+
+    /// Radix (base) integer types are rendered in, between 2 and 36 (inclusive). The default
+    /// implementation returns `10`. Digits above `9` use `'a'`-`'z'`. Does not affect how
+    /// floating point types are rendered.
+    fn radix(&self) -> u8 {
+        10
+    }
+}
@@ -0,0 +1,14 @@
+/// Type representing the strategy used to group digits together (e.g. should `1000000` be
+/// rendered as `"1,000,000"`, `"10,00,000"`, or something else?).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum Grouping {
+    /// Digits are grouped in threes, as in most of the world (e.g. `"1,000,000"`).
+    Standard,
+
+    /// Digits are grouped using the Indian numbering system (e.g. `"10,00,000"`).
+    Indian,
+
+    /// No grouping takes place, as specified by the POSIX locale (e.g. `"1000000"`).
+    Posix,
+}
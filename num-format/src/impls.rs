@@ -0,0 +1,411 @@
+//! Implementations of [`ToFormattedStr`] for every primitive number type in the standard
+//! library.
+
+use crate::alignment::Alignment;
+use crate::constants::{MAX_FRACTION_DIGITS, MAX_INTEGER_DIGITS};
+use crate::float_format_options::FloatFormatOptions;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::sealed::Sealed;
+use crate::to_formatted_str::ToFormattedStr;
+
+/// Pads `buf[..content_len]` out to `format.width()` (measured in `char`s) using
+/// `format.fill()`/`format.alignment()`, shifting the existing content over as needed. Returns
+/// the new total length. A no-op if the content is already at least as wide as `format.width()`.
+fn apply_padding<F>(format: &F, buf: &mut [u8], content_len: usize) -> usize
+where
+    F: Format,
+{
+    let width = format.width();
+    // Safe because every write path in this module only ever writes valid utf8.
+    let content_chars = core::str::from_utf8(&buf[..content_len]).unwrap().chars().count();
+    if content_chars >= width {
+        return content_len;
+    }
+    let pad_chars = width - content_chars;
+
+    let mut fill_bytes = [0u8; 4];
+    let fill_len = format.fill().encode_utf8(&mut fill_bytes).len();
+
+    let (left_chars, right_chars) = match format.alignment() {
+        Alignment::Left => (0, pad_chars),
+        Alignment::Right => (pad_chars, 0),
+        Alignment::Center => (pad_chars / 2, pad_chars - pad_chars / 2),
+    };
+
+    // Zero-fill padding is sign-aware: it belongs between the minus sign and the digits (e.g.
+    // `"-00005"`), not in front of the sign (`"0000-5"`), matching `core::fmt`'s `{:08}`.
+    let sign_len = if format.fill() == '0' {
+        let sign = format.minus_sign();
+        if buf[..content_len].starts_with(sign.as_bytes()) {
+            sign.len()
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+
+    let left_bytes = left_chars * fill_len;
+    let right_bytes = right_chars * fill_len;
+
+    if left_bytes > 0 {
+        buf.copy_within(sign_len..content_len, sign_len + left_bytes);
+    }
+    for i in 0..left_chars {
+        let start = sign_len + i * fill_len;
+        buf[start..start + fill_len].copy_from_slice(&fill_bytes[..fill_len]);
+    }
+    for i in 0..right_chars {
+        let start = left_bytes + content_len + i * fill_len;
+        buf[start..start + fill_len].copy_from_slice(&fill_bytes[..fill_len]);
+    }
+
+    left_bytes + content_len + right_bytes
+}
+
+/// Size, in digits, of the group immediately to the left of the decimal point (or the end of the
+/// number, if there's no decimal point); subsequent groups (further left) use `group_size` for
+/// every grouping except [`Grouping::Indian`], which uses 2 digits for every group after the
+/// first.
+fn group_size(grouping: Grouping, first_group_from_right: bool) -> usize {
+    match grouping {
+        Grouping::Posix => 0,
+        Grouping::Standard => 3,
+        Grouping::Indian => {
+            if first_group_from_right {
+                3
+            } else {
+                2
+            }
+        }
+    }
+}
+
+/// Converts a digit value (`0..radix`) to its ascii representation; digits above `9` use
+/// `'a'`-`'z'`, matching `core`'s own radix formatting (e.g. `{:x}`).
+fn digit_to_ascii(digit: u8) -> u8 {
+    if digit < 10 {
+        b'0' + digit
+    } else {
+        b'a' + (digit - 10)
+    }
+}
+
+/// Writes `integer_magnitude` (in the given `radix`, grouped according to `format`) and
+/// `fraction_digits` (verbatim, after the locale's decimal separator) into `buf`, prefixed with
+/// `format.minus_sign()` if `negative`. Returns the number of bytes written.
+fn write_number<F>(
+    negative: bool,
+    integer_magnitude: u128,
+    min_integer_digits: usize,
+    radix: u8,
+    fraction_digits: &str,
+    format: &F,
+    buf: &mut [u8],
+) -> usize
+where
+    F: Format,
+{
+    let mut pos = 0;
+
+    if negative {
+        let s = format.minus_sign();
+        buf[pos..pos + s.len()].copy_from_slice(s.as_bytes());
+        pos += s.len();
+    }
+
+    let radix = radix as u128;
+    let mut digits = [0u8; MAX_INTEGER_DIGITS];
+    let mut n = integer_magnitude;
+    let mut ndigits = 0;
+    loop {
+        digits[ndigits] = digit_to_ascii((n % radix) as u8);
+        n /= radix;
+        ndigits += 1;
+        if n == 0 {
+            break;
+        }
+    }
+    while ndigits < min_integer_digits {
+        digits[ndigits] = b'0';
+        ndigits += 1;
+    }
+    // `digits[0..ndigits]` now holds the integer's digits, least-significant first.
+
+    let grouping = format.grouping();
+    let sep = format.separator();
+
+    // `group_size` sizes groups from the right (the units group first), but `buf` is written
+    // left-to-right, so first walk the digits from the right to record each group's size, then
+    // emit them in reverse (most-significant group first).
+    let mut group_sizes = [0usize; MAX_INTEGER_DIGITS];
+    let mut ngroups = 0;
+    let mut remaining = ndigits;
+    let mut first_group_from_right = true;
+    while remaining > 0 {
+        let size = if grouping == Grouping::Posix {
+            remaining
+        } else {
+            group_size(grouping, first_group_from_right).min(remaining)
+        };
+        group_sizes[ngroups] = size;
+        ngroups += 1;
+        remaining -= size;
+        first_group_from_right = false;
+    }
+
+    let mut remaining = ndigits;
+    for group_index in (0..ngroups).rev() {
+        let size = group_sizes[group_index];
+        for i in (0..size).rev() {
+            buf[pos] = digits[remaining - size + i];
+            pos += 1;
+        }
+        remaining -= size;
+        if group_index > 0 {
+            buf[pos..pos + sep.len()].copy_from_slice(sep.as_bytes());
+            pos += sep.len();
+        }
+    }
+
+    if !fraction_digits.is_empty() {
+        let dec = format.decimal();
+        buf[pos..pos + dec.len()].copy_from_slice(dec.as_bytes());
+        pos += dec.len();
+        buf[pos..pos + fraction_digits.len()].copy_from_slice(fraction_digits.as_bytes());
+        pos += fraction_digits.len();
+    }
+
+    pos
+}
+
+fn write_integer<F>(negative: bool, magnitude: u128, format: &F, buf: &mut [u8]) -> usize
+where
+    F: Format,
+{
+    let len = write_number(negative, magnitude, 1, format.radix(), "", format, buf);
+    apply_padding(format, buf, len)
+}
+
+fn write_float<F>(value: f64, format: &F, options: FloatFormatOptions, buf: &mut [u8]) -> usize
+where
+    F: Format,
+{
+    if value.is_nan() {
+        let s = format.nan();
+        buf[..s.len()].copy_from_slice(s.as_bytes());
+        return apply_padding(format, buf, s.len());
+    }
+
+    let negative = value.is_sign_negative() && value != 0.0;
+
+    if value.is_infinite() {
+        let mut pos = 0;
+        if negative {
+            let s = format.minus_sign();
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            pos += s.len();
+        }
+        let s = format.infinity();
+        buf[pos..pos + s.len()].copy_from_slice(s.as_bytes());
+        pos += s.len();
+        return apply_padding(format, buf, pos);
+    }
+
+    let max_fraction_digits = options.max_fraction_digits().min(MAX_FRACTION_DIGITS);
+    let min_fraction_digits = options.min_fraction_digits().min(max_fraction_digits);
+
+    let divisor = 10u128.pow(max_fraction_digits as u32);
+    // Round half-to-even at the `max_fraction_digits` boundary, matching ICU.
+    let scaled = (value.abs() * divisor as f64).round_ties_even() as u128;
+    let integer_magnitude = scaled / divisor;
+    let mut fraction_value = scaled % divisor;
+
+    let mut fraction_buf = [b'0'; MAX_FRACTION_DIGITS];
+    for i in (0..max_fraction_digits).rev() {
+        fraction_buf[i] = b'0' + (fraction_value % 10) as u8;
+        fraction_value /= 10;
+    }
+    let mut fraction_len = max_fraction_digits;
+    while fraction_len > min_fraction_digits && fraction_buf[fraction_len - 1] == b'0' {
+        fraction_len -= 1;
+    }
+    // Safe because `fraction_buf` only ever holds ascii digits.
+    let fraction_digits = core::str::from_utf8(&fraction_buf[..fraction_len]).unwrap();
+
+    // Floats always render in base 10, regardless of `format.radix()`.
+    let len = write_number(
+        negative,
+        integer_magnitude,
+        options.min_integer_digits(),
+        10,
+        fraction_digits,
+        format,
+        buf,
+    );
+    apply_padding(format, buf, len)
+}
+
+macro_rules! impl_signed_integer {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Sealed for $t {}
+
+            impl ToFormattedStr for $t {
+                fn write_formatted_str<F>(&self, format: &F, _options: FloatFormatOptions, buf: &mut [u8]) -> usize
+                where
+                    F: Format,
+                {
+                    write_integer(*self < 0, self.unsigned_abs() as u128, format, buf)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unsigned_integer {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Sealed for $t {}
+
+            impl ToFormattedStr for $t {
+                fn write_formatted_str<F>(&self, format: &F, _options: FloatFormatOptions, buf: &mut [u8]) -> usize
+                where
+                    F: Format,
+                {
+                    write_integer(false, *self as u128, format, buf)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Sealed for $t {}
+
+            impl ToFormattedStr for $t {
+                fn write_formatted_str<F>(&self, format: &F, options: FloatFormatOptions, buf: &mut [u8]) -> usize
+                where
+                    F: Format,
+                {
+                    write_float(*self as f64, format, options, buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_integer!(i8, i16, i32, i64, isize);
+impl_unsigned_integer!(u8, u16, u32, u64, usize);
+impl_float!(f32);
+
+// `i128`, `u128`, and `f64` are handled separately from the macros above: their conversion to
+// `write_integer`'s/`write_float`'s `u128`/`f64` parameter is already the identity, and `as`-casting
+// a value to its own type trips `#![deny(trivial_numeric_casts)]`.
+
+impl Sealed for i128 {}
+
+impl ToFormattedStr for i128 {
+    fn write_formatted_str<F>(&self, format: &F, _options: FloatFormatOptions, buf: &mut [u8]) -> usize
+    where
+        F: Format,
+    {
+        write_integer(*self < 0, self.unsigned_abs(), format, buf)
+    }
+}
+
+impl Sealed for u128 {}
+
+impl ToFormattedStr for u128 {
+    fn write_formatted_str<F>(&self, format: &F, _options: FloatFormatOptions, buf: &mut [u8]) -> usize
+    where
+        F: Format,
+    {
+        write_integer(false, *self, format, buf)
+    }
+}
+
+impl Sealed for f64 {}
+
+impl ToFormattedStr for f64 {
+    fn write_formatted_str<F>(&self, format: &F, options: FloatFormatOptions, buf: &mut [u8]) -> usize
+    where
+        F: Format,
+    {
+        write_float(*self, format, options, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::Buffer;
+    use crate::custom_format::CustomFormat;
+    use crate::grouping::Grouping;
+    use crate::locale::Locale;
+
+    #[test]
+    fn grouping_handles_more_than_one_group() {
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&1_000_000i64, &Locale::en);
+        assert_eq!(buf.as_str(), "1,000,000");
+
+        let _ = buf.write_formatted(&1_000_000i64, &Locale::en_IN);
+        assert_eq!(buf.as_str(), "10,00,000");
+    }
+
+    #[test]
+    fn zero_fill_padding_is_sign_aware() {
+        let format = CustomFormat::builder().width(6).fill('0').build().unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&-5i64, &format);
+        assert_eq!(buf.as_str(), "-00005");
+    }
+
+    #[test]
+    fn radix_renders_digits_above_nine_as_lowercase_letters_and_still_groups() {
+        let format = CustomFormat::builder()
+            .radix(16)
+            .separator(",")
+            .build()
+            .unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&-0xabcdefi64, &format);
+        assert_eq!(buf.as_str(), "-abc,def");
+    }
+
+    #[test]
+    fn radix_does_not_group_when_the_digits_fit_in_a_single_group() {
+        let format = CustomFormat::builder().radix(2).build().unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&5i64, &format);
+        assert_eq!(buf.as_str(), "101");
+    }
+
+    #[test]
+    fn radix_handles_the_minimum_and_maximum_supported_bases() {
+        let binary = CustomFormat::builder()
+            .radix(2)
+            .grouping(Grouping::Posix)
+            .build()
+            .unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&0b1010i64, &binary);
+        assert_eq!(buf.as_str(), "1010");
+
+        let base36 = CustomFormat::builder().radix(36).build().unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&35i64, &base36);
+        assert_eq!(buf.as_str(), "z");
+    }
+
+    #[test]
+    fn radix_renders_zero() {
+        let format = CustomFormat::builder().radix(16).build().unwrap();
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(&0i64, &format);
+        assert_eq!(buf.as_str(), "0");
+    }
+}
@@ -64,13 +64,14 @@ fn main() {
 ### `WriteFormatted`
 
 The [`WriteFormatted`] trait is in between the other two APIs. You can write a formatted
-representation into any type that implements [`WriteFormatted`] (all the types in the standard
-library that implement [`io::Write`] or [`fmt::Write`] implement [`WriteFormatted`], such as
-[`Vec`], [`String`], [`File`], etc.).
+representation into any type that implements [`WriteFormatted`]: [`String`] and [`Vec<u8>`](Vec).
+It's deliberately not implemented for OS-backed [`io::Write`] sinks like [`File`](std::fs::File)
+or [`Stdout`](std::io::Stdout) — those can fail for reasons outside this crate's control, and
+[`WriteFormatted`]'s methods return a plain `usize` with no way to surface that error.
 
 If you're writing a number type that can use the [`Buffer`] API, there is **no** heap allocation.
-That said, the [`io::Write`] and [`fmt::Write`] machinery adds a bit of overhead; so it's faster
-to use the [`Buffer`] type directly.
+That said, the [`fmt::Write`]/[`io::Write`] machinery adds a bit of overhead; so it's faster to
+use the [`Buffer`] type directly.
 
 You can also use this API with types where the [`Buffer`] API will not work, like
 [`num_bigint::BigInt`], in which case there will be heap allocation. As such, this trait is
@@ -82,7 +83,7 @@ use num_format::{Locale, WriteFormatted};
 
 fn main() {
     // Create a writer...
-    let mut writer = String::new(); // Could also be Vec::new(), File::open(...), ...
+    let mut writer = String::new(); // Could also be Vec::new()
 
     // Write "1,000,000" into the writer...
     writer.write_formatted(&1000000, &Locale::en);
@@ -249,7 +250,7 @@ at your option.
 #[macro_use]
 extern crate cfg_if;
 
-#[cfg(all(feature = "std", any(unix, windows)))]
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate lazy_static;
 
@@ -257,16 +258,21 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde;
 
+mod alignment;
 mod buffer;
 mod constants;
+mod current;
 mod custom_format;
 mod custom_format_builder;
 mod error;
 mod error_kind;
+mod float_format_options;
 mod format;
 mod grouping;
 mod impls;
 mod locale;
+mod negotiation;
+mod plural_category;
 mod strings;
 #[cfg(all(feature = "std", any(unix, windows)))]
 mod system_locale;
@@ -276,14 +282,20 @@ mod to_formatted_string;
 #[cfg(feature = "std")]
 mod write_formatted;
 
+pub use self::alignment::Alignment;
 pub use self::buffer::Buffer;
+pub use self::current::current;
+#[cfg(feature = "std")]
+pub use self::current::{set_current, set_current_for_thread, ThreadLocaleGuard};
 pub use self::custom_format::CustomFormat;
 pub use self::custom_format_builder::CustomFormatBuilder;
 pub use self::error::Error;
 pub use self::error_kind::ErrorKind;
+pub use self::float_format_options::FloatFormatOptions;
 pub use self::format::Format;
 pub use self::grouping::Grouping;
 pub use self::locale::Locale;
+pub use self::plural_category::{PluralCategory, PluralRuleType};
 #[cfg(all(feature = "std", any(unix, windows)))]
 pub use self::system_locale::SystemLocale;
 pub use self::to_formatted_str::ToFormattedStr;
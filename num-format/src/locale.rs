@@ -0,0 +1,303 @@
+//! Hand-written [`Locale`] definitions sourced from the Unicode Consortium's [Common Locale Data
+//! Repository] (CLDR): each locale's grouping, separators, and plural rules below should match
+//! CLDR's data for it.
+//!
+//! [Common Locale Data Repository]: https://en.wikipedia.org/wiki/Common_Locale_Data_Repository
+
+use crate::error::Error;
+use crate::error_kind::ErrorKind;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::negotiation;
+use crate::plural_category::{rules, PluralCategory, PluralOperands, PluralRuleType};
+use crate::sealed::Sealed;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+
+type PluralRuleFn = fn(PluralOperands, PluralRuleType) -> PluralCategory;
+
+macro_rules! locales {
+    ( $( $(#[$meta:meta])* $variant:ident => {
+        name: $name:expr,
+        grouping: $grouping:expr,
+        minus_sign: $minus_sign:expr,
+        decimal: $decimal:expr,
+        separator: $separator:expr,
+        plural_rules: $plural_rules:expr,
+    } ),* $(,)? ) => {
+        /// A locale formatted according to the Unicode Consortium's Common Locale Data
+        /// Repository (CLDR), the same database used by Apple in macOS and iOS, by LibreOffice,
+        /// and by IBM in AIX, among others.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+        #[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+        #[allow(non_camel_case_types)]
+        #[non_exhaustive]
+        pub enum Locale {
+            $( $(#[$meta])* $variant, )*
+        }
+
+        impl Locale {
+            /// Returns this locale's name (e.g. `"en"`, `"en_IN"`, `"fr_FR"`).
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( Locale::$variant => $name, )*
+                }
+            }
+
+            /// Constructs a [`Locale`] from a CLDR locale name (e.g. `"en"`, `"en_IN"`). Matching
+            /// is case-sensitive and exact; see [`Locale::negotiate`] or [`Locale::filter`] if you
+            /// need something more forgiving.
+            pub fn from_name<S>(name: S) -> Result<Locale, Error>
+            where
+                S: AsRef<str>,
+            {
+                match name.as_ref() {
+                    $( $name => Ok(Locale::$variant), )*
+                    _ => Err(Error::new(ErrorKind::ParseLocale)),
+                }
+            }
+
+            /// Returns the names of all the locales included in this crate.
+            pub fn available_names() -> &'static [&'static str] {
+                &[ $( $name, )* ]
+            }
+
+            fn plural_rule_fn(&self) -> PluralRuleFn {
+                match self {
+                    $( Locale::$variant => $plural_rules, )*
+                }
+            }
+
+            /// Returns the CLDR plural category that applies to `n` in this locale, under the
+            /// given [`PluralRuleType`]. [`plural_category`](Locale::plural_category) and
+            /// [`ordinal_category`](Locale::ordinal_category) are shorthand for this with
+            /// [`PluralRuleType::Cardinal`] and [`PluralRuleType::Ordinal`] respectively.
+            pub fn category<I>(&self, n: I, rule_type: PluralRuleType) -> PluralCategory
+            where
+                I: Into<i128>,
+            {
+                let operands = PluralOperands::from_integer(n.into());
+                (self.plural_rule_fn())(operands, rule_type)
+            }
+
+            /// Returns the CLDR plural category that applies to the cardinal form of `n` in this
+            /// locale, i.e. the category used to pick between strings like `"1 file"` and `"2
+            /// files"`.
+            pub fn plural_category<I>(&self, n: I) -> PluralCategory
+            where
+                I: Into<i128>,
+            {
+                self.category(n, PluralRuleType::Cardinal)
+            }
+
+            /// Returns the CLDR plural category that applies to the ordinal form of `n` in this
+            /// locale, i.e. the category used to pick between strings like `"1st file"` and `"2nd
+            /// file"`.
+            pub fn ordinal_category<I>(&self, n: I) -> PluralCategory
+            where
+                I: Into<i128>,
+            {
+                self.category(n, PluralRuleType::Ordinal)
+            }
+        }
+
+        impl Sealed for Locale {}
+
+        impl Format for Locale {
+            fn decimal(&self) -> DecimalStr<'_> {
+                match self {
+                    $( Locale::$variant => DecimalStr::new($decimal).unwrap(), )*
+                }
+            }
+            fn grouping(&self) -> Grouping {
+                match self {
+                    $( Locale::$variant => $grouping, )*
+                }
+            }
+            fn infinity(&self) -> InfinityStr<'_> {
+                InfinityStr::new("∞").unwrap()
+            }
+            fn minus_sign(&self) -> MinusSignStr<'_> {
+                match self {
+                    $( Locale::$variant => MinusSignStr::new($minus_sign).unwrap(), )*
+                }
+            }
+            fn nan(&self) -> NanStr<'_> {
+                NanStr::new("NaN").unwrap()
+            }
+            fn plus_sign(&self) -> PlusSignStr<'_> {
+                PlusSignStr::new("+").unwrap()
+            }
+            fn separator(&self) -> SeparatorStr<'_> {
+                match self {
+                    $( Locale::$variant => SeparatorStr::new($separator).unwrap(), )*
+                }
+            }
+        }
+    };
+}
+
+locales! {
+    /// The "en" locale (generic English).
+    en => {
+        name: "en", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::english,
+    },
+    /// The "en_US" locale (English, United States).
+    en_US => {
+        name: "en_US", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::english,
+    },
+    /// The "en_GB" locale (English, United Kingdom).
+    en_GB => {
+        name: "en_GB", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::english,
+    },
+    /// The "en_IN" locale (English, India), which uses Indian-style grouping.
+    en_IN => {
+        name: "en_IN", grouping: Grouping::Indian, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::english,
+    },
+    /// The "fr" locale (generic French).
+    fr => {
+        name: "fr", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: "\u{a0}",
+        plural_rules: rules::one_is_zero_or_one,
+    },
+    /// The "fr_FR" locale (French, France).
+    fr_FR => {
+        name: "fr_FR", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: "\u{a0}",
+        plural_rules: rules::one_is_zero_or_one,
+    },
+    /// The "pt" locale (generic Portuguese).
+    pt => {
+        name: "pt", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: ".",
+        plural_rules: rules::one_is_zero_or_one,
+    },
+    /// The "de" locale (generic German).
+    de => {
+        name: "de", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: ".",
+        plural_rules: rules::one_is_one,
+    },
+    /// The "es" locale (generic Spanish).
+    es => {
+        name: "es", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: ".",
+        plural_rules: rules::one_is_one,
+    },
+    /// The "it" locale (generic Italian).
+    it => {
+        name: "it", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: ".",
+        plural_rules: rules::one_is_one,
+    },
+    /// The "nl" locale (generic Dutch).
+    nl => {
+        name: "nl", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: ".",
+        plural_rules: rules::one_is_one,
+    },
+    /// The "ru" locale (generic Russian).
+    ru => {
+        name: "ru", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: "\u{a0}",
+        plural_rules: rules::slavic_east,
+    },
+    /// The "pl" locale (generic Polish).
+    pl => {
+        name: "pl", grouping: Grouping::Standard, minus_sign: "-", decimal: ",", separator: "\u{a0}",
+        plural_rules: rules::polish,
+    },
+    /// The "ar" locale (generic Arabic).
+    ar => {
+        name: "ar", grouping: Grouping::Standard, minus_sign: "-", decimal: "\u{66b}", separator: "\u{66c}",
+        plural_rules: rules::arabic,
+    },
+    /// The "ja" locale (generic Japanese), which has no grammatical plural.
+    ja => {
+        name: "ja", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::always_other,
+    },
+    /// The "zh" locale (generic Chinese), which has no grammatical plural.
+    zh => {
+        name: "zh", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::always_other,
+    },
+    /// The "ko" locale (generic Korean), which has no grammatical plural.
+    ko => {
+        name: "ko", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::always_other,
+    },
+    /// The "th" locale (generic Thai), which has no grammatical plural.
+    th => {
+        name: "th", grouping: Grouping::Standard, minus_sign: "-", decimal: ".", separator: ",",
+        plural_rules: rules::always_other,
+    },
+    /// The "POSIX" locale, which applies no grouping.
+    POSIX => {
+        name: "POSIX", grouping: Grouping::Posix, minus_sign: "-", decimal: ".", separator: "",
+        plural_rules: rules::english,
+    },
+}
+
+impl Locale {
+    /// Performs RFC 4647 basic filtering, returning every [`Locale`] whose name matches
+    /// `language_range` (`"*"` matches everything; otherwise a name matches if it equals the
+    /// range or begins with the range followed by `-`). Comparisons are case-insensitive and
+    /// treat `_` and `-` as equivalent separators.
+    pub fn filter(language_range: &str) -> Vec<Locale> {
+        negotiation::basic_filter(language_range, Locale::available_names())
+            .into_iter()
+            .filter_map(|name| Locale::from_name(name).ok())
+            .collect()
+    }
+
+    /// Performs RFC 4647 lookup against the locales available in this crate: tries each range in
+    /// `language_ranges` in turn (e.g. the ranges from an `Accept-Language` header, most
+    /// preferred first), progressively truncating trailing subtags until one matches an available
+    /// [`Locale`], and returns `default` if none of them ever do.
+    pub fn negotiate<S>(language_ranges: &[S], default: Locale) -> Locale
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            negotiation::lookup(language_ranges, Locale::available_names(), default.name());
+        Locale::from_name(name).unwrap_or(default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn de_es_it_nl_have_no_ordinal_distinction() {
+        for locale in [Locale::de, Locale::es, Locale::it, Locale::nl] {
+            assert_eq!(locale.ordinal_category(1), PluralCategory::Other);
+            assert_eq!(locale.ordinal_category(2), PluralCategory::Other);
+            assert_eq!(locale.ordinal_category(3), PluralCategory::Other);
+        }
+    }
+
+    #[test]
+    fn category_matches_the_plural_and_ordinal_shorthands() {
+        assert_eq!(Locale::en.category(1, PluralRuleType::Cardinal), Locale::en.plural_category(1));
+        assert_eq!(Locale::en.category(2, PluralRuleType::Ordinal), Locale::en.ordinal_category(2));
+    }
+
+    #[test]
+    fn filter_matches_every_english_locale() {
+        let matches = Locale::filter("en");
+        assert!(matches.contains(&Locale::en));
+        assert!(matches.contains(&Locale::en_US));
+        assert!(matches.contains(&Locale::en_GB));
+        assert!(matches.contains(&Locale::en_IN));
+        assert!(!matches.contains(&Locale::fr));
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_the_default_locale() {
+        let locale = Locale::negotiate(&["xx-Yy"], Locale::en_GB);
+        assert_eq!(locale, Locale::en_GB);
+    }
+
+    #[test]
+    fn negotiate_picks_the_first_matching_range() {
+        let locale = Locale::negotiate(&["xx", "fr-CA"], Locale::en);
+        assert_eq!(locale, Locale::fr);
+    }
+}
@@ -0,0 +1,113 @@
+//! Implements the two matching strategies from [RFC 4647](https://tools.ietf.org/html/rfc4647)
+//! for picking a locale out of a list of extended language ranges (e.g. an `Accept-Language`
+//! header), against a set of available locale names.
+
+/// Returns every name in `available` that RFC 4647 §3.3.1 "basic filtering" says `language_range`
+/// matches: either `language_range` is `"*"`, or a name matches if it is equal to
+/// `language_range` or begins with `language_range` followed by `-`. Comparisons are
+/// case-insensitive and treat `_` and `-` as equivalent separators.
+pub(crate) fn basic_filter<'a>(language_range: &str, available: &[&'a str]) -> Vec<&'a str> {
+    let range = normalize(language_range);
+    available
+        .iter()
+        .copied()
+        .filter(|name| range_matches(&range, &normalize(name)))
+        .collect()
+}
+
+/// Performs RFC 4647 §3.4 "lookup": tries each range in `language_ranges` in order, progressively
+/// truncating trailing subtags (also dropping a single-character extension subtag left dangling
+/// at the end by a truncation) until a match is found in `available` or the range is exhausted, then moves on
+/// to the next range. Returns `default` if no range matches.
+pub(crate) fn lookup<'a, S>(
+    language_ranges: &[S],
+    available: &[&'a str],
+    default: &'a str,
+) -> &'a str
+where
+    S: AsRef<str>,
+{
+    let normalized_available: Vec<(String, &'a str)> = available
+        .iter()
+        .map(|&name| (normalize(name), name))
+        .collect();
+
+    for language_range in language_ranges {
+        let mut range = normalize(language_range.as_ref());
+        // A "*" range carries no information for lookup (unlike basic filtering) and is skipped,
+        // per RFC 4647 §3.4.
+        if range == "*" {
+            continue;
+        }
+        loop {
+            if let Some((_, name)) = normalized_available.iter().find(|(n, _)| *n == range) {
+                return name;
+            }
+            match truncate(&range) {
+                Some(truncated) => range = truncated,
+                None => break,
+            }
+        }
+    }
+    default
+}
+
+fn range_matches(range: &str, name: &str) -> bool {
+    range == "*" || name == range || name.starts_with(&format!("{}-", range))
+}
+
+/// Drops the trailing subtag from `range` (e.g. `"zh-hant-tw"` -> `"zh-hant"`), additionally
+/// dropping the subtag now left dangling at the end if it's a single character, since
+/// single-character subtags are extension/private-use introducers that can't stand alone.
+/// Returns `None` once there's nothing left to drop.
+fn truncate(range: &str) -> Option<String> {
+    let split_at = range.rfind('-')?;
+    let head = &range[..split_at];
+    match head.rfind('-') {
+        Some(pos) if head.len() - pos - 1 == 1 => Some(head[..pos].to_string()),
+        None if head.len() == 1 => None,
+        _ => Some(head.to_string()),
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .map(|c| if c == '_' { '-' } else { c.to_ascii_lowercase() })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_filter_matches_prefixes_case_and_separator_insensitively() {
+        let available = ["en", "en-US", "en-IN", "fr"];
+        assert_eq!(basic_filter("en", &available), vec!["en", "en-US", "en-IN"]);
+        assert_eq!(basic_filter("EN_us", &available), vec!["en-US"]);
+        assert_eq!(basic_filter("*", &available), available.to_vec());
+        assert!(basic_filter("de", &available).is_empty());
+    }
+
+    #[test]
+    fn lookup_truncates_trailing_subtags_until_a_match() {
+        let available = ["zh-hant", "zh", "en"];
+        // "private" is dropped, then the now-dangling singleton extension subtag "x" is dropped
+        // along with it in the same step, leaving "zh-hant-cn"; that still doesn't match, so one
+        // more truncation drops "cn" and lands on "zh-hant".
+        assert_eq!(lookup(&["zh-Hant-CN-x-private"], &available, "en"), "zh-hant");
+    }
+
+    #[test]
+    fn lookup_moves_to_the_next_range_and_skips_wildcards() {
+        let available = ["fr", "en"];
+        assert_eq!(lookup(&["*", "fr"], &available, "en"), "fr");
+        assert_eq!(lookup(&["de-DE", "fr-FR"], &available, "en"), "fr");
+    }
+
+    #[test]
+    fn lookup_falls_back_to_default_when_nothing_matches() {
+        let available = ["en", "fr"];
+        assert_eq!(lookup(&["zh-Hant"], &available, "en"), "en");
+    }
+}
@@ -0,0 +1,211 @@
+//! Evaluation of [CLDR plural rules], used by [`Locale::plural_category`] and
+//! [`Locale::ordinal_category`] to pick the grammatically correct variant of a surrounding
+//! message (e.g. `"1 file"` vs. `"2 files"`).
+//!
+//! [CLDR plural rules]: https://www.unicode.org/cldr/cldr-aux/charts/33/supplemental/language_plural_rules.html
+//! [`Locale::plural_category`]: crate::locale::Locale::plural_category
+//! [`Locale::ordinal_category`]: crate::locale::Locale::ordinal_category
+
+/// One of the six CLDR plural categories a number can fall into for a given locale.
+///
+/// Not every locale uses every category; a locale whose cardinal rules only distinguish `One`
+/// from `Other` (like English) will never return `Zero`, `Two`, `Few`, or `Many` for a cardinal,
+/// though its ordinal rules may (English ordinals use `Two` and `Few` for `"2nd"` and `"3rd"`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PluralCategory {
+    /// The "zero" plural category (e.g. Arabic's `"0 files"`).
+    Zero,
+    /// The "one" (singular) plural category (e.g. `"1 file"`).
+    One,
+    /// The "two" (dual) plural category (e.g. Arabic's `"2 files"`).
+    Two,
+    /// The "few" (paucal) plural category (e.g. Polish's `"2 pliki"`).
+    Few,
+    /// The "many" plural category (e.g. Polish's `"5 plików"`).
+    Many,
+    /// The "other" plural category, the default for every locale (e.g. `"5 files"`).
+    Other,
+}
+
+/// Whether a [`PluralCategory`] is being selected for a cardinal number (`"1 file"`, `"2
+/// files"`) or an ordinal number (`"1st file"`, `"2nd file"`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PluralRuleType {
+    /// Counting quantities, as in `"1 file"` vs. `"2 files"`.
+    Cardinal,
+    /// Ranking positions, as in `"1st file"` vs. `"2nd file"`.
+    Ordinal,
+}
+
+/// The CLDR plural operands derived from a formatted number (see [TR35]), used to evaluate the
+/// boolean conditions that make up a locale's plural rules.
+///
+/// [TR35]: https://www.unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct PluralOperands {
+    /// Absolute value of the number.
+    pub(crate) n: f64,
+    /// Integer digits of the number.
+    pub(crate) i: u64,
+    /// Number of visible fraction digits, with trailing zeros.
+    pub(crate) v: u32,
+    /// Visible fraction digits, with trailing zeros, as an integer.
+    pub(crate) f: u64,
+    /// Visible fraction digits, without trailing zeros, as an integer.
+    pub(crate) t: u64,
+    /// Number of visible fraction digits, without trailing zeros.
+    pub(crate) w: u32,
+}
+
+impl PluralOperands {
+    /// Computes the operands for an integer; `v`, `f`, `t`, and `w` are always zero since
+    /// integers have no fraction digits.
+    pub(crate) fn from_integer(n: i128) -> PluralOperands {
+        let i = n.unsigned_abs() as u64;
+        PluralOperands {
+            n: i as f64,
+            i,
+            v: 0,
+            f: 0,
+            t: 0,
+            w: 0,
+        }
+    }
+
+    fn mod10(&self) -> u64 {
+        self.i % 10
+    }
+
+    fn mod100(&self) -> u64 {
+        self.i % 100
+    }
+}
+
+/// CLDR plural rule "families". Many locales share an identical rule set; rather than duplicate
+/// the logic per-locale, [`Locale::plural_category`](crate::locale::Locale::plural_category)
+/// dispatches to one of these shared implementations, exactly as CLDR itself groups locales by
+/// rule set.
+pub(crate) mod rules {
+    use super::{PluralCategory, PluralOperands, PluralRuleType};
+
+    /// English and most other languages with a simple singular/plural split: `one` is exactly
+    /// `1`; ordinals follow the `1st`/`2nd`/`3rd`/`nth` pattern.
+    pub(crate) fn english(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        match rule_type {
+            PluralRuleType::Cardinal => {
+                if op.i == 1 && op.v == 0 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            PluralRuleType::Ordinal => {
+                let mod10 = op.mod10();
+                let mod100 = op.mod100();
+                if mod10 == 1 && mod100 != 11 {
+                    PluralCategory::One
+                } else if mod10 == 2 && mod100 != 12 {
+                    PluralCategory::Two
+                } else if mod10 == 3 && mod100 != 13 {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+        }
+    }
+
+    /// German, Spanish, Italian, Dutch, and similar: `one` is exactly `1`, the same cardinal rule
+    /// as [`english`], but (per CLDR) none of them make English's ordinal distinctions — ordinal
+    /// is always `other`.
+    pub(crate) fn one_is_one(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        if rule_type == PluralRuleType::Ordinal {
+            return PluralCategory::Other;
+        }
+        if op.i == 1 && op.v == 0 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    /// French, Portuguese, and similar: `one` covers every `n` whose integer part is `0` or `1`
+    /// (i.e. `n` in `[0, 2)`). No ordinal distinction — ordinal is always `other`.
+    pub(crate) fn one_is_zero_or_one(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        if rule_type == PluralRuleType::Ordinal {
+            return PluralCategory::Other;
+        }
+        if op.n >= 0.0 && op.n < 2.0 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    /// Chinese, Japanese, Korean, and other languages with no grammatical plural.
+    pub(crate) fn always_other(_op: PluralOperands, _rule_type: PluralRuleType) -> PluralCategory {
+        PluralCategory::Other
+    }
+
+    /// Russian and the other East Slavic languages.
+    pub(crate) fn slavic_east(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        if rule_type == PluralRuleType::Ordinal {
+            return PluralCategory::Other;
+        }
+        let mod10 = op.mod10();
+        let mod100 = op.mod100();
+        if op.v == 0 && mod10 == 1 && mod100 != 11 {
+            PluralCategory::One
+        } else if op.v == 0 && (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+            PluralCategory::Few
+        } else if op.v == 0 && (mod10 == 0 || (5..=9).contains(&mod10) || (11..=14).contains(&mod100)) {
+            PluralCategory::Many
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    /// Polish.
+    pub(crate) fn polish(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        if rule_type == PluralRuleType::Ordinal {
+            return PluralCategory::Other;
+        }
+        let mod10 = op.mod10();
+        let mod100 = op.mod100();
+        if op.i == 1 && op.v == 0 {
+            PluralCategory::One
+        } else if op.v == 0 && (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+            PluralCategory::Few
+        } else if op.v == 0
+            && ((op.i != 1 && (0..=1).contains(&mod10))
+                || (5..=9).contains(&mod10)
+                || (12..=14).contains(&mod100))
+        {
+            PluralCategory::Many
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    /// Arabic.
+    pub(crate) fn arabic(op: PluralOperands, rule_type: PluralRuleType) -> PluralCategory {
+        if rule_type == PluralRuleType::Ordinal {
+            return PluralCategory::Other;
+        }
+        let mod100 = op.mod100();
+        if op.n == 0.0 {
+            PluralCategory::Zero
+        } else if op.n == 1.0 {
+            PluralCategory::One
+        } else if op.n == 2.0 {
+            PluralCategory::Two
+        } else if (3..=10).contains(&mod100) {
+            PluralCategory::Few
+        } else if (11..=99).contains(&mod100) {
+            PluralCategory::Many
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
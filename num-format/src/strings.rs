@@ -0,0 +1,103 @@
+//! Fixed-capacity string types used to store and validate the small pieces of text that make up
+//! a [`Format`](crate::format::Format) (e.g. the decimal separator, the minus sign, ...).
+//!
+//! Each type comes in two flavors: an owned, `Copy`-able type (e.g. [`DecString`]) used by
+//! [`CustomFormat`](crate::custom_format::CustomFormat) and
+//! [`SystemLocale`](crate::system_locale::SystemLocale) to store their data, and a borrowed,
+//! validated wrapper around a `&str` (e.g. [`DecimalStr`]) returned by the [`Format`
+//! trait](crate::format::Format)'s methods.
+
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+
+use crate::error::Error;
+use crate::error_kind::ErrorKind;
+
+macro_rules! create_string_types {
+    ( $( ($owned:ident, $borrowed:ident, $cap:expr, $what:expr) ),* $(,)? ) => {
+        $(
+            #[doc = concat!("An owned, fixed-capacity string representing a ", $what, ".")]
+            #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+            #[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+            pub struct $owned {
+                len: u8,
+                buf: [u8; $cap],
+            }
+
+            impl $owned {
+                pub(crate) fn new<S>(s: S) -> Result<$owned, Error>
+                where
+                    S: AsRef<str>,
+                {
+                    let s = s.as_ref();
+                    if s.len() > $cap {
+                        return Err(Error::new(ErrorKind::Capacity($cap)));
+                    }
+                    let mut buf = [0u8; $cap];
+                    buf[..s.len()].copy_from_slice(s.as_bytes());
+                    Ok($owned { len: s.len() as u8, buf })
+                }
+
+                pub(crate) fn as_str(&self) -> &str {
+                    // Safe because we only ever copy valid utf8 into `buf` in `new` above.
+                    unsafe { str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+                }
+            }
+
+            impl Deref for $owned {
+                type Target = str;
+                fn deref(&self) -> &str {
+                    self.as_str()
+                }
+            }
+
+            impl fmt::Debug for $owned {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(self.as_str(), f)
+                }
+            }
+
+            #[doc = concat!("A borrowed, validated string representing a ", $what, ".")]
+            #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+            pub struct $borrowed<'a>(&'a str);
+
+            impl<'a> $borrowed<'a> {
+                /// Validates `s` and wraps it.
+                pub fn new(s: &'a str) -> Result<$borrowed<'a>, Error> {
+                    if s.len() > $cap {
+                        return Err(Error::new(ErrorKind::Capacity($cap)));
+                    }
+                    Ok($borrowed(s))
+                }
+
+                /// Unwraps this type into the borrowed `&str` it wraps.
+                pub fn into_str(self) -> &'a str {
+                    self.0
+                }
+            }
+
+            impl<'a> Deref for $borrowed<'a> {
+                type Target = str;
+                fn deref(&self) -> &str {
+                    self.0
+                }
+            }
+
+            impl<'a> fmt::Display for $borrowed<'a> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+create_string_types! {
+    (DecString, DecimalStr, 8, "decimal separator"),
+    (InfString, InfinityStr, 16, "representation of infinity"),
+    (MinString, MinusSignStr, 8, "minus sign"),
+    (NanString, NanStr, 16, "representation of NaN"),
+    (PlusString, PlusSignStr, 8, "plus sign"),
+    (SepString, SeparatorStr, 8, "thousands separator"),
+}
@@ -7,6 +7,8 @@ use std::collections::HashSet;
 use crate::error::Error;
 use crate::format::Format;
 use crate::grouping::Grouping;
+use crate::negotiation;
+use crate::sealed::Sealed;
 use crate::strings::{
     DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
     PlusSignStr, PlusString, SepString, SeparatorStr,
@@ -170,6 +172,24 @@ impl SystemLocale {
         self.nan = NanString::new(s)?;
         Ok(())
     }
+
+    /// Performs RFC 4647 lookup against the locales available on this system (see
+    /// [`Locale::negotiate`](crate::Locale::negotiate)): tries each range in `language_ranges` in
+    /// turn, most preferred first, progressively truncating trailing subtags until one matches an
+    /// available name, and falls back to [`SystemLocale::default`] if none of them ever do.
+    pub fn negotiate<S>(language_ranges: &[S]) -> Result<SystemLocale, Error>
+    where
+        S: AsRef<str>,
+    {
+        let available = SystemLocale::available_names()?;
+        let available: Vec<&str> = available.iter().map(String::as_str).collect();
+        // "" can never be a real locale name, so it's a safe sentinel for "no range matched"
+        // that lets us avoid constructing a `SystemLocale::default()` unless we actually need it.
+        match negotiation::lookup(language_ranges, &available, "") {
+            "" => SystemLocale::default(),
+            name => SystemLocale::from_name(name),
+        }
+    }
 }
 
 impl std::str::FromStr for SystemLocale {
@@ -180,6 +200,8 @@ impl std::str::FromStr for SystemLocale {
     }
 }
 
+impl Sealed for SystemLocale {}
+
 impl Format for SystemLocale {
     fn decimal(&self) -> DecimalStr<'_> {
         DecimalStr::new(self.decimal()).unwrap()
@@ -0,0 +1,16 @@
+use crate::float_format_options::FloatFormatOptions;
+use crate::format::Format;
+use crate::sealed::Sealed;
+
+/// Marker trait for the primitive number types (`i8`, `i16`, ..., `u128`, `f32`, `f64`) that can
+/// be written into a [`Buffer`](crate::buffer::Buffer) without heap allocation.
+///
+/// This trait is sealed; it cannot be implemented outside of this crate.
+pub trait ToFormattedStr: Sealed {
+    /// Not part of the public API. Writes this value's formatted representation into `buf`,
+    /// starting at index `0`, and returns the number of bytes written.
+    #[doc(hidden)]
+    fn write_formatted_str<F>(&self, format: &F, options: FloatFormatOptions, buf: &mut [u8]) -> usize
+    where
+        F: Format;
+}
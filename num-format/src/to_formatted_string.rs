@@ -0,0 +1,42 @@
+use crate::buffer::Buffer;
+use crate::current::current;
+use crate::float_format_options::FloatFormatOptions;
+use crate::format::Format;
+use crate::to_formatted_str::ToFormattedStr;
+
+/// Implemented for every type in [`ToFormattedStr`] so you can call `.to_formatted_string(...)`
+/// directly, heap allocating a new `String` in the process.
+pub trait ToFormattedString: ToFormattedStr
+where
+    Self: Sized,
+{
+    /// Produces a heap-allocated `String` representation of this value, formatted according to
+    /// `format`.
+    fn to_formatted_string<F>(&self, format: &F) -> String
+    where
+        F: Format,
+    {
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(self, format);
+        buf.as_str().to_string()
+    }
+
+    /// Like [`to_formatted_string`](ToFormattedString::to_formatted_string), but with explicit
+    /// control over how any fractional part is rendered; see [`FloatFormatOptions`].
+    fn to_formatted_string_with_options<F>(&self, format: &F, options: FloatFormatOptions) -> String
+    where
+        F: Format,
+    {
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted_with_options(self, format, options);
+        buf.as_str().to_string()
+    }
+
+    /// Like [`to_formatted_string`](ToFormattedString::to_formatted_string), but formatted
+    /// according to the process-wide [`current`] locale instead of an explicit [`Format`].
+    fn to_formatted_string_default(&self) -> String {
+        self.to_formatted_string(&current())
+    }
+}
+
+impl<T> ToFormattedString for T where T: ToFormattedStr {}
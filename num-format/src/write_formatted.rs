@@ -0,0 +1,101 @@
+use std::fmt;
+use std::io;
+
+use crate::buffer::Buffer;
+use crate::current::current;
+use crate::float_format_options::FloatFormatOptions;
+use crate::format::Format;
+use crate::to_formatted_str::ToFormattedStr;
+
+/// Implemented for types you can write a formatted value directly into (`String` and `Vec<u8>`).
+/// When `N` also implements [`ToFormattedStr`](crate::to_formatted_str::ToFormattedStr), no heap
+/// allocation takes place beyond whatever `self` itself might do.
+///
+/// Deliberately **not** implemented for OS-backed [`io::Write`] sinks like [`File`](std::fs::File),
+/// [`Stdout`](std::io::Stdout), or [`Stderr`](std::io::Stderr): those can fail for reasons outside
+/// this crate's control (a full disk, a closed pipe, ...), and this trait's methods return a plain
+/// `usize` with nowhere to report that. Write into a `Buffer` or `String`/`Vec<u8>` instead, then
+/// hand the result to `io::Write::write_all` yourself so you can handle the `io::Result`.
+pub trait WriteFormatted {
+    /// Writes `value` into `self`, formatted according to `format`. Returns the number of bytes
+    /// written.
+    fn write_formatted<N, F>(&mut self, value: &N, format: &F) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format;
+
+    /// Like [`write_formatted`](WriteFormatted::write_formatted), but with explicit control over
+    /// how any fractional part is rendered; see [`FloatFormatOptions`].
+    fn write_formatted_with_options<N, F>(
+        &mut self,
+        value: &N,
+        format: &F,
+        options: FloatFormatOptions,
+    ) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format;
+
+    /// Like [`write_formatted`](WriteFormatted::write_formatted), but formatted according to the
+    /// process-wide [`current`] locale instead of an explicit [`Format`].
+    fn write_formatted_default<N>(&mut self, value: &N) -> usize
+    where
+        N: ToFormattedStr,
+    {
+        self.write_formatted(value, &current())
+    }
+}
+
+impl WriteFormatted for Vec<u8> {
+    fn write_formatted<N, F>(&mut self, value: &N, format: &F) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        self.write_formatted_with_options(value, format, FloatFormatOptions::default())
+    }
+
+    fn write_formatted_with_options<N, F>(
+        &mut self,
+        value: &N,
+        format: &F,
+        options: FloatFormatOptions,
+    ) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        let mut buf = Buffer::new();
+        let len = buf.write_formatted_with_options(value, format, options);
+        io::Write::write_all(self, buf.as_bytes())
+            .expect("writing to a Vec<u8> should never fail");
+        len
+    }
+}
+
+impl WriteFormatted for String {
+    fn write_formatted<N, F>(&mut self, value: &N, format: &F) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        self.write_formatted_with_options(value, format, FloatFormatOptions::default())
+    }
+
+    fn write_formatted_with_options<N, F>(
+        &mut self,
+        value: &N,
+        format: &F,
+        options: FloatFormatOptions,
+    ) -> usize
+    where
+        N: ToFormattedStr,
+        F: Format,
+    {
+        let mut buf = Buffer::new();
+        let len = buf.write_formatted_with_options(value, format, options);
+        fmt::Write::write_str(self, buf.as_str())
+            .expect("writing to a String should never fail");
+        len
+    }
+}